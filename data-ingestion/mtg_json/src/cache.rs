@@ -0,0 +1,68 @@
+//! On-disk metadata tracking the `ETag` of the last successful download, so
+//! repeat calls can skip re-fetching and re-extracting unchanged archives.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::MtgJsonError;
+
+const METADATA_FILE: &str = ".mtgjson-cache.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheMetadata {
+    etag: Option<String>,
+}
+
+/// The result of a conditional download via [`crate::download_mtg_json_if_changed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadOutcome {
+    /// The server reported new data and it was downloaded and extracted.
+    Updated,
+    /// The server confirmed (via `304 Not Modified`) that the cache is current;
+    /// existing files were left untouched.
+    AlreadyCurrent,
+}
+
+fn metadata_path(cache_dir: &Path) -> PathBuf {
+    cache_dir.join(METADATA_FILE)
+}
+
+/// Reads the previously stored `ETag` for `cache_dir`, if any.
+pub fn read_etag(cache_dir: &Path) -> Option<String> {
+    let contents = fs::read_to_string(metadata_path(cache_dir)).ok()?;
+    let metadata: CacheMetadata = serde_json::from_str(&contents).ok()?;
+    metadata.etag
+}
+
+/// Persists `etag` as the cache's current `ETag`.
+pub fn write_etag(cache_dir: &Path, etag: Option<&str>) -> Result<(), MtgJsonError> {
+    let metadata = CacheMetadata {
+        etag: etag.map(str::to_owned),
+    };
+    let contents = serde_json::to_string_pretty(&metadata).expect("CacheMetadata is serializable");
+    fs::write(metadata_path(cache_dir), contents)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::temp_dir;
+
+    #[test]
+    fn etag_round_trips_through_disk() {
+        let dir = temp_dir("cache");
+
+        assert_eq!(read_etag(&dir), None);
+
+        write_etag(&dir, Some("\"abc123\"")).unwrap();
+        assert_eq!(read_etag(&dir), Some("\"abc123\"".to_string()));
+
+        write_etag(&dir, None).unwrap();
+        assert_eq!(read_etag(&dir), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,73 @@
+//! Compression/archive formats MTGJSON distributes its exports in.
+
+/// A compression or archive format MTGJSON may wrap an export in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// A gzipped tarball of many per-set `*.json` files (MTGJSON's `AllSets`/`AllPrintings` tarballs).
+    TarGz,
+    /// A zip archive of per-set `*.json` files.
+    Zip,
+    /// A single bzip2-compressed JSON file.
+    Bz2,
+    /// A single xz-compressed JSON file.
+    Xz,
+    /// A single uncompressed JSON file.
+    Json,
+}
+
+impl Compression {
+    /// The URL suffix MTGJSON uses for exports in this format.
+    pub fn url_suffix(self) -> &'static str {
+        match self {
+            Compression::TarGz => ".tar.gz",
+            Compression::Zip => ".zip",
+            Compression::Bz2 => ".bz2",
+            Compression::Xz => ".xz",
+            Compression::Json => "",
+        }
+    }
+
+    /// Infers the compression format from a URL's file extension.
+    pub fn detect(url: &str) -> Self {
+        if url.ends_with(".tar.gz") || url.ends_with(".tgz") {
+            Compression::TarGz
+        } else if url.ends_with(".zip") {
+            Compression::Zip
+        } else if url.ends_with(".bz2") {
+            Compression::Bz2
+        } else if url.ends_with(".xz") {
+            Compression::Xz
+        } else {
+            Compression::Json
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_known_extensions() {
+        assert_eq!(
+            Compression::detect("https://mtgjson.com/files/AllSets.json.tar.gz"),
+            Compression::TarGz
+        );
+        assert_eq!(
+            Compression::detect("https://mtgjson.com/api/v5/AllPrintings.json.zip"),
+            Compression::Zip
+        );
+        assert_eq!(
+            Compression::detect("https://mtgjson.com/api/v5/AllPrintings.json.bz2"),
+            Compression::Bz2
+        );
+        assert_eq!(
+            Compression::detect("https://mtgjson.com/api/v5/AllPrintings.json.xz"),
+            Compression::Xz
+        );
+        assert_eq!(
+            Compression::detect("https://mtgjson.com/api/v5/DOM.json"),
+            Compression::Json
+        );
+    }
+}
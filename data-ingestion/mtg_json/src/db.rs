@@ -0,0 +1,222 @@
+//! In-memory query layer over a parsed MTGJSON extract.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::MtgJsonError;
+use crate::model::{Card, Envelope, Set};
+
+/// Deserializes every `*.json` entry under `dir` (searched recursively) into
+/// a `Set`, keyed by set code.
+///
+/// Each file is expected to be MTGJSON's per-set shape: an
+/// `{"meta": ..., "data": <Set>}` envelope, as extracted from `AllSets`/a
+/// single set's API file. Non-JSON files (and anything that fails to parse
+/// in that shape) are skipped rather than failing the whole load, since
+/// MTGJSON archives can contain companion files (e.g. checksums) alongside
+/// the per-set data. The recursive search accommodates `AllSets`'s extracted
+/// layout, which nests per-set files under a subdirectory rather than
+/// dropping them at `dir`'s root.
+pub fn parse_sets(dir: &Path) -> Result<HashMap<String, Set>, MtgJsonError> {
+    let mut sets = HashMap::new();
+    visit_set_files(dir, &mut sets)?;
+    Ok(sets)
+}
+
+fn visit_set_files(dir: &Path, sets: &mut HashMap<String, Set>) -> Result<(), MtgJsonError> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            visit_set_files(&path, sets)?;
+            continue;
+        }
+
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let contents = fs::read_to_string(&path)?;
+        if let Ok(envelope) = serde_json::from_str::<Envelope<Set>>(&contents) {
+            sets.insert(envelope.data.code.clone(), envelope.data);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the contents of an `AllPrintings.json` file (or the decompressed
+/// contents of its `.bz2`/`.xz`/plain-`.json` variants) into its `Set` map.
+///
+/// Unlike [`parse_sets`], which expects one `Set` per file, `AllPrintings`
+/// wraps every set, keyed by code, under a single envelope's `data`.
+pub fn parse_all_printings(contents: &str) -> Result<HashMap<String, Set>, MtgJsonError> {
+    let envelope: Envelope<HashMap<String, Set>> = serde_json::from_str(contents)?;
+    Ok(envelope.data)
+}
+
+/// A queryable, in-memory view over a parsed MTGJSON extract.
+pub struct MtgDatabase {
+    sets: HashMap<String, Set>,
+}
+
+impl MtgDatabase {
+    /// Parses every per-set `*.json` file in `dir` and builds a database from them.
+    pub fn load(dir: &Path) -> Result<Self, MtgJsonError> {
+        Ok(Self {
+            sets: parse_sets(dir)?,
+        })
+    }
+
+    /// Parses an `AllPrintings.json` file at `path` and builds a database from it.
+    pub fn load_all_printings(path: &Path) -> Result<Self, MtgJsonError> {
+        let contents = fs::read_to_string(path)?;
+        Ok(Self {
+            sets: parse_all_printings(&contents)?,
+        })
+    }
+
+    /// Wraps an already-parsed set map, e.g. one produced by [`parse_sets`].
+    pub fn from_sets(sets: HashMap<String, Set>) -> Self {
+        Self { sets }
+    }
+
+    /// Looks up a set by its code (e.g. `"DOM"`).
+    pub fn set(&self, code: &str) -> Option<&Set> {
+        self.sets.get(code)
+    }
+
+    /// Returns the card named `name` from its earliest-released loaded
+    /// printing (ties broken by set code), since MTGJSON reprints most card
+    /// names across many sets and iteration order over the underlying set
+    /// map is otherwise unspecified.
+    pub fn card_by_name(&self, name: &str) -> Option<&Card> {
+        let mut printings: Vec<&Set> = self
+            .sets
+            .values()
+            .filter(|set| set.cards.iter().any(|card| card.name == name))
+            .collect();
+        printings.sort_by(|a, b| (&a.release_date, &a.code).cmp(&(&b.release_date, &b.code)));
+
+        printings
+            .first()
+            .and_then(|set| set.cards.iter().find(|card| card.name == name))
+    }
+
+    /// Returns every card in the given set, or an empty slice if the set isn't loaded.
+    pub fn cards_in_set(&self, code: &str) -> &[Card] {
+        self.sets
+            .get(code)
+            .map(|set| set.cards.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Returns every card across all loaded sets matching `predicate`.
+    pub fn search<P>(&self, predicate: P) -> Vec<&Card>
+    where
+        P: Fn(&Card) -> bool,
+    {
+        self.sets
+            .values()
+            .flat_map(|set| &set.cards)
+            .filter(|card| predicate(card))
+            .collect()
+    }
+
+    /// Returns every card across all loaded sets that is legal in `format`.
+    pub fn legal_in_format(&self, format: &str) -> Vec<&Card> {
+        self.search(|card| card.legalities.is_legal_in(format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Identifiers, Legalities};
+    use crate::testutil::temp_dir;
+
+    const DOM_SET_JSON: &str = r#"{
+        "meta": {"version": "5.2.1", "date": "2024-01-01"},
+        "data": {
+            "code": "DOM",
+            "name": "Dominaria",
+            "releaseDate": "2018-04-27",
+            "cards": [{"name": "Shivan Fire"}]
+        }
+    }"#;
+
+    #[test]
+    fn parse_sets_unwraps_the_meta_data_envelope() {
+        let dir = temp_dir("db");
+        fs::write(dir.join("DOM.json"), DOM_SET_JSON).unwrap();
+
+        let sets = parse_sets(&dir).unwrap();
+
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets["DOM"].name, "Dominaria");
+        assert_eq!(sets["DOM"].cards[0].name, "Shivan Fire");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn parse_all_printings_unwraps_the_meta_data_envelope() {
+        let contents = r#"{
+            "meta": {"version": "5.2.1", "date": "2024-01-01"},
+            "data": {
+                "DOM": {
+                    "code": "DOM",
+                    "name": "Dominaria",
+                    "releaseDate": "2018-04-27",
+                    "cards": []
+                }
+            }
+        }"#;
+
+        let sets = parse_all_printings(contents).unwrap();
+
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets["DOM"].name, "Dominaria");
+    }
+
+    fn reprinted_card_set(code: &str, release_date: &str) -> Set {
+        Set {
+            code: code.to_string(),
+            name: code.to_string(),
+            release_date: release_date.to_string(),
+            cards: vec![Card {
+                name: "Shivan Fire".to_string(),
+                types: Vec::new(),
+                colors: Vec::new(),
+                mana_cost: None,
+                text: None,
+                legalities: Legalities::default(),
+                identifiers: Identifiers::default(),
+                prices: None,
+            }],
+        }
+    }
+
+    #[test]
+    fn card_by_name_picks_the_earliest_printing_regardless_of_insertion_order() {
+        let dom = reprinted_card_set("DOM", "2018-04-27");
+        let m19 = reprinted_card_set("M19", "2018-07-13");
+
+        let forward = MtgDatabase::from_sets(HashMap::from([
+            ("DOM".to_string(), dom.clone()),
+            ("M19".to_string(), m19.clone()),
+        ]));
+        let reversed = MtgDatabase::from_sets(HashMap::from([
+            ("M19".to_string(), m19),
+            ("DOM".to_string(), dom),
+        ]));
+
+        for db in [forward, reversed] {
+            let card = db.card_by_name("Shivan Fire").unwrap();
+            assert_eq!(card.name, "Shivan Fire");
+            assert_eq!(db.set("DOM").unwrap().cards[0].name, card.name);
+        }
+    }
+}
@@ -0,0 +1,413 @@
+//! A configured HTTP client for talking to MTGJSON, wired up for streaming
+//! progress reporting and format-aware extraction.
+
+use std::fs::{self, File};
+use std::io::{copy, Cursor, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use bzip2::read::BzDecoder;
+use flate2::read::GzDecoder;
+use reqwest::blocking::{Client, Response};
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::redirect::Policy;
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+use tar::Archive;
+use xz2::read::XzDecoder;
+use zip::ZipArchive;
+
+use crate::cache::{self, DownloadOutcome};
+use crate::compression::Compression;
+use crate::error::MtgJsonError;
+use crate::file::MtgJsonFile;
+use crate::progress::ProgressListener;
+
+const USER_AGENT: &str = "mtg-json-downloader/0.1";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A configured client for downloading MTGJSON exports.
+///
+/// Construct one with [`Downloader::new`] (or [`Downloader::with_timeout`] for
+/// a non-default timeout), then reuse it across calls.
+pub struct Downloader {
+    client: Client,
+}
+
+impl Downloader {
+    /// Builds a downloader with the default timeout (5 minutes).
+    pub fn new() -> Result<Self, MtgJsonError> {
+        Self::with_timeout(Some(DEFAULT_TIMEOUT))
+    }
+
+    /// Builds a downloader with an explicit timeout, or none (`None`) to wait
+    /// indefinitely on slow connections.
+    pub fn with_timeout(timeout: Option<Duration>) -> Result<Self, MtgJsonError> {
+        let client = Client::builder()
+            .user_agent(USER_AGENT)
+            .redirect(Policy::limited(10))
+            .timeout(timeout)
+            .build()
+            .map_err(|source| MtgJsonError::Network {
+                url: "<client setup>".to_string(),
+                source,
+            })?;
+
+        Ok(Self { client })
+    }
+
+    /// Downloads `file` in the requested `compression` and writes it into
+    /// `dest_dir`, which is created if it doesn't already exist.
+    ///
+    /// The compression actually used for extraction is re-detected from the
+    /// resulting URL, since some files (e.g. the legacy `AllSets` export)
+    /// only ship in one format regardless of what's requested.
+    pub fn download_to(
+        &self,
+        file: MtgJsonFile,
+        compression: Compression,
+        dest_dir: &Path,
+        progress: &mut dyn ProgressListener,
+    ) -> Result<(), MtgJsonError> {
+        fs::create_dir_all(dest_dir)?;
+
+        let url = file.url(compression);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .and_then(Response::error_for_status)
+            .map_err(|source| MtgJsonError::Network {
+                url: url.clone(),
+                source,
+            })?;
+
+        let (bytes, checksum) = read_with_progress(response, progress)?;
+        verify_checksum(&self.client, &url, &checksum)?;
+
+        extract(&bytes, &file, Compression::detect(&url), dest_dir, progress)
+    }
+
+    /// Downloads and extracts `file` in the requested `compression` into
+    /// `cache_dir`, but skips the transfer entirely if MTGJSON reports (via
+    /// `304 Not Modified`) that nothing has changed since the last call.
+    ///
+    /// `cache_dir` is created if it doesn't already exist. The server's
+    /// `ETag` is stored alongside the extracted files so subsequent calls can
+    /// send it back as `If-None-Match`.
+    pub fn download_if_changed(
+        &self,
+        file: MtgJsonFile,
+        compression: Compression,
+        cache_dir: &Path,
+        progress: &mut dyn ProgressListener,
+    ) -> Result<DownloadOutcome, MtgJsonError> {
+        fs::create_dir_all(cache_dir)?;
+
+        let url = file.url(compression);
+        let mut request = self.client.get(&url);
+        if let Some(etag) = cache::read_etag(cache_dir) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let response = request.send().map_err(|source| MtgJsonError::Network {
+            url: url.clone(),
+            source,
+        })?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(DownloadOutcome::AlreadyCurrent);
+        }
+
+        let response = response
+            .error_for_status()
+            .map_err(|source| MtgJsonError::Network {
+                url: url.clone(),
+                source,
+            })?;
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+
+        let (bytes, checksum) = read_with_progress(response, progress)?;
+        verify_checksum(&self.client, &url, &checksum)?;
+        extract(&bytes, &file, Compression::detect(&url), cache_dir, progress)?;
+        cache::write_etag(cache_dir, etag.as_deref())?;
+
+        Ok(DownloadOutcome::Updated)
+    }
+}
+
+/// Reads `response`'s body to completion, reporting progress as bytes arrive
+/// and hashing them incrementally so the full body never needs a second pass
+/// just to compute its checksum.
+fn read_with_progress(
+    mut response: Response,
+    progress: &mut dyn ProgressListener,
+) -> Result<(Vec<u8>, String), MtgJsonError> {
+    let total = response.content_length();
+    let mut bytes = Vec::new();
+    let mut chunk = [0u8; DOWNLOAD_CHUNK_SIZE];
+    let mut downloaded = 0u64;
+    let mut hasher = Sha256::new();
+
+    loop {
+        let n = response.read(&mut chunk)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&chunk[..n]);
+        bytes.extend_from_slice(&chunk[..n]);
+        downloaded += n as u64;
+        progress.on_download_progress(downloaded, total);
+    }
+
+    Ok((bytes, format!("{:x}", hasher.finalize())))
+}
+
+/// Fetches MTGJSON's `.sha256` sidecar for `url` and checks it against
+/// `actual_checksum`, the hex digest computed while streaming the body in.
+///
+/// Only the legacy `AllSets` tarball is guaranteed to have a published
+/// sidecar; per-set API files and most non-`AllSets` compressions don't
+/// publish one, so a `404` is treated as "nothing to verify" rather than
+/// a hard failure.
+fn verify_checksum(client: &Client, url: &str, actual_checksum: &str) -> Result<(), MtgJsonError> {
+    let checksum_url = format!("{url}.sha256");
+    let response = client
+        .get(&checksum_url)
+        .send()
+        .map_err(|source| MtgJsonError::Network {
+            url: checksum_url.clone(),
+            source,
+        })?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(());
+    }
+
+    let text = response
+        .error_for_status()
+        .and_then(Response::text)
+        .map_err(|source| MtgJsonError::Network {
+            url: checksum_url,
+            source,
+        })?;
+
+    // MTGJSON's .sha256 files contain the hex digest, optionally followed by the file name.
+    let expected_checksum = text.split_whitespace().next().unwrap_or_default().to_lowercase();
+
+    if actual_checksum != expected_checksum {
+        return Err(MtgJsonError::ChecksumMismatch {
+            url: url.to_string(),
+            expected: expected_checksum,
+            actual: actual_checksum.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Resolves an archive entry's path against `dest_dir`, rejecting absolute
+/// paths and `..` components so a crafted entry can't write outside of it.
+fn safe_join(dest_dir: &Path, entry_path: &Path) -> Result<PathBuf, MtgJsonError> {
+    if entry_path
+        .components()
+        .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_) | Component::RootDir))
+    {
+        return Err(MtgJsonError::UnsafeArchiveEntry(entry_path.to_path_buf()));
+    }
+
+    Ok(dest_dir.join(entry_path))
+}
+
+/// Unpacks `bytes` into `dest_dir` using the decompressor matching `compression`.
+fn extract(
+    bytes: &[u8],
+    file: &MtgJsonFile,
+    compression: Compression,
+    dest_dir: &Path,
+    progress: &mut dyn ProgressListener,
+) -> Result<(), MtgJsonError> {
+    match compression {
+        Compression::TarGz => extract_tar_gz(bytes, dest_dir, progress),
+        Compression::Zip => extract_zip(bytes, dest_dir, progress),
+        Compression::Bz2 => write_single_json(BzDecoder::new(bytes), file, dest_dir, progress),
+        Compression::Xz => write_single_json(XzDecoder::new(bytes), file, dest_dir, progress),
+        Compression::Json => write_single_json(bytes, file, dest_dir, progress),
+    }
+}
+
+/// Extracts a gzipped tarball's contents into `dest_dir`, which must already exist.
+fn extract_tar_gz(
+    bytes: &[u8],
+    dest_dir: &Path,
+    progress: &mut dyn ProgressListener,
+) -> Result<(), MtgJsonError> {
+    let mut tar_gz = GzDecoder::new(bytes);
+    let mut archive = Archive::new(&mut tar_gz);
+
+    let mut entries_done = 0u64;
+    for file in archive.entries()? {
+        let mut file = file?;
+        let entry_path = file.path()?.into_owned();
+        let dest_path = safe_join(dest_dir, &entry_path)?;
+
+        if file.header().entry_type().is_dir() {
+            fs::create_dir_all(&dest_path)?;
+            continue;
+        }
+
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut output_file = File::create(dest_path)?;
+        copy(&mut file, &mut output_file)?;
+
+        entries_done += 1;
+        progress.on_extract_progress(entries_done, None);
+    }
+
+    Ok(())
+}
+
+/// Extracts a zip archive's contents into `dest_dir`, which must already exist.
+fn extract_zip(
+    bytes: &[u8],
+    dest_dir: &Path,
+    progress: &mut dyn ProgressListener,
+) -> Result<(), MtgJsonError> {
+    let mut archive =
+        ZipArchive::new(Cursor::new(bytes)).map_err(|source| MtgJsonError::Archive(source.to_string()))?;
+    let total_entries = archive.len() as u64;
+
+    for i in 0..archive.len() {
+        let mut entry = archive
+            .by_index(i)
+            .map_err(|source| MtgJsonError::Archive(source.to_string()))?;
+        let entry_path = entry
+            .enclosed_name()
+            .ok_or_else(|| MtgJsonError::UnsafeArchiveEntry(PathBuf::from(entry.name())))?
+            .to_path_buf();
+        let dest_path = safe_join(dest_dir, &entry_path)?;
+
+        if entry.is_dir() {
+            fs::create_dir_all(&dest_path)?;
+        } else {
+            if let Some(parent) = dest_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut output_file = File::create(dest_path)?;
+            copy(&mut entry, &mut output_file)?;
+        }
+
+        progress.on_extract_progress(i as u64 + 1, Some(total_entries));
+    }
+
+    Ok(())
+}
+
+/// Drains `reader` and writes it as `{file.base_name()}.json` in `dest_dir`.
+fn write_single_json(
+    mut reader: impl Read,
+    file: &MtgJsonFile,
+    dest_dir: &Path,
+    progress: &mut dyn ProgressListener,
+) -> Result<(), MtgJsonError> {
+    let mut contents = Vec::new();
+    reader.read_to_end(&mut contents)?;
+
+    let mut output_file = File::create(dest_dir.join(format!("{}.json", file.base_name())))?;
+    output_file.write_all(&contents)?;
+
+    progress.on_extract_progress(1, Some(1));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_join_allows_plain_relative_entries() {
+        let dest = Path::new("/tmp/mtgjson-dest");
+        let joined = safe_join(dest, Path::new("DOM.json")).unwrap();
+        assert_eq!(joined, dest.join("DOM.json"));
+    }
+
+    #[test]
+    fn safe_join_rejects_parent_dir_traversal() {
+        let dest = Path::new("/tmp/mtgjson-dest");
+        let err = safe_join(dest, Path::new("../../etc/passwd")).unwrap_err();
+        assert!(matches!(err, MtgJsonError::UnsafeArchiveEntry(_)));
+    }
+
+    #[test]
+    fn safe_join_rejects_absolute_paths() {
+        let dest = Path::new("/tmp/mtgjson-dest");
+        let err = safe_join(dest, Path::new("/etc/passwd")).unwrap_err();
+        assert!(matches!(err, MtgJsonError::UnsafeArchiveEntry(_)));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn safe_join_rejects_windows_prefix() {
+        let dest = Path::new(r"C:\dest");
+        let err = safe_join(dest, Path::new(r"C:\Windows\System32")).unwrap_err();
+        assert!(matches!(err, MtgJsonError::UnsafeArchiveEntry(_)));
+    }
+
+    /// Builds a gzipped tarball containing one entry, `AllSetFiles/DOM.json`,
+    /// matching the nesting MTGJSON's real `AllSets` tarball uses rather than
+    /// a flat archive root.
+    fn nested_tar_gz(contents: &[u8]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "AllSetFiles/DOM.json", contents)
+            .unwrap();
+        let tar_bytes = builder.into_inner().unwrap();
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&tar_bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn extract_preserves_nested_entries_so_parse_sets_can_still_find_them() {
+        let dom_json = br#"{
+            "meta": {"version": "5.2.1", "date": "2024-01-01"},
+            "data": {
+                "code": "DOM",
+                "name": "Dominaria",
+                "releaseDate": "2018-04-27",
+                "cards": [{"name": "Shivan Fire"}]
+            }
+        }"#;
+        let bytes = nested_tar_gz(dom_json);
+        let dest_dir = crate::testutil::temp_dir("extract-nested");
+
+        extract(
+            &bytes,
+            &MtgJsonFile::AllSets,
+            Compression::TarGz,
+            &dest_dir,
+            &mut crate::progress::NoopProgress,
+        )
+        .unwrap();
+
+        assert!(dest_dir.join("AllSetFiles").join("DOM.json").is_file());
+
+        let sets = crate::db::parse_sets(&dest_dir).unwrap();
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets["DOM"].name, "Dominaria");
+
+        fs::remove_dir_all(&dest_dir).unwrap();
+    }
+}
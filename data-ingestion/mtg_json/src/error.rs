@@ -0,0 +1,35 @@
+//! Error types shared across the download/parse pipeline.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Everything that can go wrong while fetching and unpacking an MTGJSON archive.
+#[derive(Debug, Error)]
+pub enum MtgJsonError {
+    #[error("network request to {url} failed: {source}")]
+    Network {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("checksum mismatch for {url}: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("archive entry {0:?} would escape the destination directory")]
+    UnsafeArchiveEntry(PathBuf),
+
+    #[error("failed to read archive: {0}")]
+    Archive(String),
+
+    #[error("failed to parse MTGJSON data: {0}")]
+    Parse(#[from] serde_json::Error),
+}
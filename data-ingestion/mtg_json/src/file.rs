@@ -0,0 +1,67 @@
+//! Which MTGJSON export to download.
+
+use crate::compression::Compression;
+
+pub(crate) const ALL_SETS_URL: &str = "https://mtgjson.com/files/AllSets.json.tar.gz";
+const API_BASE: &str = "https://mtgjson.com/api/v5";
+
+/// Selects a specific MTGJSON distribution to download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MtgJsonFile {
+    /// The current recommended export: every set, every printing.
+    AllPrintings,
+    /// The deprecated full-database export. Only ever shipped as a gzipped
+    /// tarball, so its URL ignores the requested [`Compression`].
+    AllSets,
+    /// A single set's file (e.g. `MtgJsonFile::Set("DOM".into())` for `DOM.json`).
+    Set(String),
+}
+
+impl MtgJsonFile {
+    /// The MTGJSON URL this file is fetched from in the given `compression`.
+    pub fn url(&self, compression: Compression) -> String {
+        match self {
+            MtgJsonFile::AllPrintings => {
+                format!("{API_BASE}/AllPrintings.json{}", compression.url_suffix())
+            }
+            MtgJsonFile::AllSets => ALL_SETS_URL.to_string(),
+            MtgJsonFile::Set(code) => format!("{API_BASE}/{code}.json{}", compression.url_suffix()),
+        }
+    }
+
+    /// The base file name (no extension) used when writing single-file
+    /// (non-tarball, non-zip) downloads to disk, e.g. `"AllPrintings"`.
+    pub fn base_name(&self) -> String {
+        match self {
+            MtgJsonFile::AllPrintings => "AllPrintings".to_string(),
+            MtgJsonFile::AllSets => "AllSets".to_string(),
+            MtgJsonFile::Set(code) => code.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_printings_url_reflects_requested_compression() {
+        assert_eq!(
+            MtgJsonFile::AllPrintings.url(Compression::Zip),
+            "https://mtgjson.com/api/v5/AllPrintings.json.zip"
+        );
+    }
+
+    #[test]
+    fn all_sets_url_ignores_requested_compression() {
+        assert_eq!(MtgJsonFile::AllSets.url(Compression::Zip), ALL_SETS_URL);
+    }
+
+    #[test]
+    fn set_url_includes_code_and_compression() {
+        assert_eq!(
+            MtgJsonFile::Set("DOM".to_string()).url(Compression::Json),
+            "https://mtgjson.com/api/v5/DOM.json"
+        );
+    }
+}
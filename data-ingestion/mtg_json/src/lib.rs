@@ -0,0 +1,52 @@
+//! A typed, query-able client library for [MTGJSON](https://mtgjson.com)
+//! exports: download them (optionally only when changed), extract them, and
+//! load the result into an in-memory [`MtgDatabase`].
+
+mod cache;
+mod compression;
+mod db;
+mod downloader;
+mod error;
+mod file;
+mod model;
+mod progress;
+#[cfg(test)]
+mod testutil;
+
+use std::path::Path;
+
+pub use cache::DownloadOutcome;
+pub use compression::Compression;
+pub use db::{parse_sets, MtgDatabase};
+pub use downloader::Downloader;
+pub use error::MtgJsonError;
+pub use file::MtgJsonFile;
+pub use model::{Card, Identifiers, Legalities, Prices, Set};
+pub use progress::{NoopProgress, ProgressListener};
+
+/// Downloads `file` in the given `compression` into `dest_dir`, using a
+/// default-configured [`Downloader`] and no progress reporting. See
+/// [`Downloader::download_to`] for more control.
+pub fn download_mtg_json_to(
+    file: MtgJsonFile,
+    compression: Compression,
+    dest_dir: &Path,
+) -> Result<(), MtgJsonError> {
+    Downloader::new()?.download_to(file, compression, dest_dir, &mut NoopProgress)
+}
+
+/// Downloads the legacy `AllSets` archive into the current directory.
+pub fn download_mtg_json() -> Result<(), MtgJsonError> {
+    download_mtg_json_to(MtgJsonFile::AllSets, Compression::TarGz, Path::new("."))
+}
+
+/// Downloads and extracts `file` in the given `compression` into `cache_dir`,
+/// skipping the transfer if MTGJSON reports the cache is already current. See
+/// [`Downloader::download_if_changed`] for progress reporting.
+pub fn download_mtg_json_if_changed(
+    file: MtgJsonFile,
+    compression: Compression,
+    cache_dir: &Path,
+) -> Result<DownloadOutcome, MtgJsonError> {
+    Downloader::new()?.download_if_changed(file, compression, cache_dir, &mut NoopProgress)
+}
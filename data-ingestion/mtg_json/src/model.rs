@@ -0,0 +1,109 @@
+//! Typed representations of the subset of the MTGJSON schema this crate consumes.
+//!
+//! These mirror the field names MTGJSON uses in its `AllSets`/`AllPrintings`
+//! exports (see <https://mtgjson.com/data-models/>). Every export wraps its
+//! payload in an [`Envelope`]; see `db.rs` for where that's unwrapped.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// MTGJSON wraps every export's payload in this `{"meta": ..., "data": ...}`
+/// envelope, whether the payload is one `Set` (per-set files like `DOM.json`)
+/// or a `HashMap<String, Set>` keyed by code (`AllPrintings.json`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Envelope<T> {
+    pub meta: Meta,
+    pub data: T,
+}
+
+/// The `meta` block MTGJSON includes alongside every export's `data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Meta {
+    pub version: String,
+    pub date: String,
+}
+
+/// A single Magic: The Gathering set, as found under `data` in one
+/// `<CODE>.json` file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Set {
+    pub code: String,
+    pub name: String,
+    #[serde(rename = "releaseDate")]
+    pub release_date: String,
+    #[serde(default)]
+    pub cards: Vec<Card>,
+}
+
+/// A single card printing within a set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Card {
+    pub name: String,
+    #[serde(default)]
+    pub types: Vec<String>,
+    #[serde(default)]
+    pub colors: Vec<String>,
+    #[serde(rename = "manaCost", default)]
+    pub mana_cost: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub legalities: Legalities,
+    #[serde(default)]
+    pub identifiers: Identifiers,
+    #[serde(default)]
+    pub prices: Option<Prices>,
+}
+
+/// Format legality, keyed by lowercase format name (`"standard"`, `"modern"`, ...).
+///
+/// MTGJSON encodes this as a flat object with arbitrary format keys, so we
+/// store it as a map rather than one field per format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Legalities(pub HashMap<String, String>);
+
+impl Legalities {
+    pub fn is_legal_in(&self, format: &str) -> bool {
+        self.0
+            .get(format)
+            .map(|status| status.eq_ignore_ascii_case("legal"))
+            .unwrap_or(false)
+    }
+}
+
+/// Cross-references to other catalogs (Scryfall, TCGPlayer, multiverse, ...).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Identifiers {
+    #[serde(rename = "scryfallId", default)]
+    pub scryfall_id: Option<String>,
+    #[serde(rename = "multiverseId", default)]
+    pub multiverse_id: Option<String>,
+    #[serde(rename = "tcgplayerProductId", default)]
+    pub tcgplayer_product_id: Option<String>,
+}
+
+/// Pricing data, which MTGJSON nests under provider/finish/date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Prices {
+    #[serde(default)]
+    pub paper: HashMap<String, HashMap<String, f64>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_legal_in_matches_case_insensitively() {
+        let mut statuses = HashMap::new();
+        statuses.insert("modern".to_string(), "Legal".to_string());
+        statuses.insert("standard".to_string(), "Banned".to_string());
+        let legalities = Legalities(statuses);
+
+        assert!(legalities.is_legal_in("modern"));
+        assert!(!legalities.is_legal_in("standard"));
+        assert!(!legalities.is_legal_in("pioneer"));
+    }
+}
@@ -0,0 +1,23 @@
+//! Pluggable progress reporting for the download/extract pipeline.
+
+/// Receives progress updates for a download and extraction.
+///
+/// Implement this to wire up a progress bar (e.g. with `indicatif`) or log
+/// output; [`NoopProgress`] is used when the caller doesn't care.
+pub trait ProgressListener {
+    /// Called as response bytes arrive. `total` is `None` if the server
+    /// didn't send a `Content-Length`.
+    fn on_download_progress(&mut self, downloaded: u64, total: Option<u64>) {
+        let _ = (downloaded, total);
+    }
+
+    /// Called after each archive entry is extracted.
+    fn on_extract_progress(&mut self, entries_done: u64, total_entries: Option<u64>) {
+        let _ = (entries_done, total_entries);
+    }
+}
+
+/// A [`ProgressListener`] that ignores every update.
+pub struct NoopProgress;
+
+impl ProgressListener for NoopProgress {}
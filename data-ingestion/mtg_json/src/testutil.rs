@@ -0,0 +1,14 @@
+//! Shared helpers for this crate's unit tests.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Creates and returns a fresh, empty directory under the system temp dir,
+/// named `mtg_json-{prefix}-test-<nonce>` so parallel tests don't collide.
+pub(crate) fn temp_dir(prefix: &str) -> PathBuf {
+    let nonce = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let dir = std::env::temp_dir().join(format!("mtg_json-{prefix}-test-{nonce}"));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}